@@ -0,0 +1,382 @@
+use anyhow::Context;
+use defs::ids::LanguageElementId;
+use diagnostics::ToOption;
+use semantic::db::SemanticGroup;
+use semantic::types::{ConcreteTypeId, TypeLongId};
+use semantic::TypeId;
+
+use crate::contract::{get_external_functions, starknet_keccak, ContractDeclaration};
+use crate::transformer::array_element_type;
+
+/// A structural description of a Cairo parameter type, resolved once from the semantic
+/// DB (mirroring `transformer::serialize_arg`'s own dispatch) so each backend can render
+/// calldata-packing code without re-deriving structure from a formatted type name.
+pub enum TypeShape {
+    Felt252,
+    U256,
+    Bool,
+    ByteArray,
+    /// The `()` return/payload type: packs to zero felts.
+    Unit,
+    Array(Box<TypeShape>),
+    Struct { name: String, fields: Vec<(String, TypeShape)> },
+    Enum { name: String, variants: Vec<(String, TypeShape)> },
+    /// A type whose members this generator doesn't model further - an extern scalar
+    /// other than the ones above (e.g. `ContractAddress`), or any other type not listed
+    /// above, such as a non-empty tuple, which is packed as a single value. Structs,
+    /// enums, arrays, and `ByteArray`s always resolve to their own variant above and
+    /// never fall through to this one.
+    Opaque,
+}
+
+/// Resolves `ty` into a [`TypeShape`], recursing into struct members, enum variant
+/// payloads, and array/span elements the same way `transformer::serialize_arg` does.
+fn resolve_type_shape(db: &(dyn SemanticGroup + 'static), ty: TypeId) -> anyhow::Result<TypeShape> {
+    let ty_str = ty.format(db.upcast());
+    match ty_str.as_str() {
+        "felt252" => return Ok(TypeShape::Felt252),
+        "u256" => return Ok(TypeShape::U256),
+        "bool" => return Ok(TypeShape::Bool),
+        "ByteArray" => return Ok(TypeShape::ByteArray),
+        "()" => return Ok(TypeShape::Unit),
+        _ => {}
+    }
+
+    if let Some(element_ty) = array_element_type(db, ty) {
+        return Ok(TypeShape::Array(Box::new(resolve_type_shape(db, element_ty)?)));
+    }
+
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            let members = db
+                .concrete_struct_members(concrete_struct_id)
+                .to_option()
+                .with_context(|| format!("Failed to get the members of `{ty_str}`."))?;
+            let fields = members
+                .iter()
+                .map(|(name, member)| Ok((name.to_string(), resolve_type_shape(db, member.ty)?)))
+                .collect::<anyhow::Result<_>>()?;
+            Ok(TypeShape::Struct { name: ty_str, fields })
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            let variants = db
+                .concrete_enum_variants(concrete_enum_id)
+                .to_option()
+                .with_context(|| format!("Failed to get the variants of `{ty_str}`."))?;
+            let variants = variants
+                .iter()
+                .map(|variant| {
+                    Ok((
+                        variant.id.name(db.upcast()).to_string(),
+                        resolve_type_shape(db, variant.ty)?,
+                    ))
+                })
+                .collect::<anyhow::Result<_>>()?;
+            Ok(TypeShape::Enum { name: ty_str, variants })
+        }
+        _ => Ok(TypeShape::Opaque),
+    }
+}
+
+/// A single parameter of a [`MethodBinding`]: its name, the backend's native type it was
+/// mapped to, and its resolved [`TypeShape`] for calldata packing.
+pub struct ParamBinding {
+    pub name: String,
+    pub mapped_type: String,
+    pub shape: TypeShape,
+}
+
+/// A single external-function binding: its name, computed selector, and parameters.
+pub struct MethodBinding {
+    pub name: String,
+    pub selector: String,
+    pub params: Vec<ParamBinding>,
+}
+
+/// The generated bindings for a single contract, ready to be written to disk.
+pub struct BindingsModule {
+    pub contract_name: String,
+    pub file_extension: &'static str,
+    pub source: String,
+}
+
+/// Knows how to map Cairo types to a target language, pack a parameter's [`TypeShape`]
+/// into calldata, and render a caller struct from a contract's collected
+/// [`MethodBinding`]s.
+pub trait BindingsBackend {
+    /// The file extension conventionally used for generated source (e.g. `"rs"`, `"ts"`).
+    fn extension(&self) -> &'static str;
+
+    /// Maps a Cairo type, as it appears in the ABI, to the backend's native type.
+    fn map_type(&self, cairo_type: &str) -> String;
+
+    /// Emits the statement(s) that pack the value bound to `value_expr` - recursing into
+    /// `shape`'s own fields/variants/elements - into the `calldata` accumulator.
+    fn pack_shape(&self, value_expr: &str, shape: &TypeShape) -> String;
+
+    /// Renders the full caller struct for a contract from its collected bindings.
+    fn render(&self, contract_name: &str, methods: &[MethodBinding]) -> String;
+}
+
+/// Generates typed client bindings for every contract declaration, using `backend` to
+/// map parameter types, pack calldata, and render the final source text.
+pub fn generate_bindings(
+    db: &(dyn SemanticGroup + 'static),
+    contracts: &[ContractDeclaration],
+    backend: &dyn BindingsBackend,
+) -> anyhow::Result<Vec<BindingsModule>> {
+    let mut modules = vec![];
+    for contract in contracts {
+        let contract_name = contract.submodule_id.name(db.upcast()).to_string();
+        let methods = collect_methods(db, contract, backend)?;
+        modules.push(BindingsModule {
+            source: backend.render(&contract_name, &methods),
+            file_extension: backend.extension(),
+            contract_name,
+        });
+    }
+    Ok(modules)
+}
+
+/// Collects the [`MethodBinding`]s for every external function of `contract`.
+fn collect_methods(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+    backend: &dyn BindingsBackend,
+) -> anyhow::Result<Vec<MethodBinding>> {
+    let mut methods = vec![];
+    for function_id in get_external_functions(db, contract)? {
+        let name = function_id.name(db.upcast());
+        let signature = db
+            .free_function_signature(function_id)
+            .to_option()
+            .with_context(|| format!("Failed to get the signature of `{name}`."))?;
+
+        let params = signature
+            .params
+            .iter()
+            .filter(|param| param.name != "self")
+            .map(|param| {
+                let cairo_type = param.ty.format(db.upcast());
+                let mapped_type = backend.map_type(&cairo_type);
+                let shape = resolve_type_shape(db, param.ty)
+                    .with_context(|| format!("Failed to resolve the type of `{}`.", param.name))?;
+                Ok(ParamBinding { name: param.name.to_string(), mapped_type, shape })
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        methods.push(MethodBinding {
+            name: name.to_string(),
+            selector: starknet_keccak(name.as_bytes()).to_str_radix(16),
+            params,
+        });
+    }
+    Ok(methods)
+}
+
+/// Emits a Rust caller struct, one inherent method per external function, mapping
+/// `felt252`/`u256`/`bool` to their native Rust counterparts and packing every parameter
+/// - including nested structs, enums, `ByteArray`s, and arrays - into the returned
+/// calldata.
+pub struct RustBackend;
+
+impl BindingsBackend for RustBackend {
+    fn extension(&self) -> &'static str {
+        "rs"
+    }
+
+    fn map_type(&self, cairo_type: &str) -> String {
+        match cairo_type {
+            "felt252" => "starknet::core::types::FieldElement".to_string(),
+            "u256" => "starknet::core::types::U256".to_string(),
+            "bool" => "bool".to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    fn pack_shape(&self, value_expr: &str, shape: &TypeShape) -> String {
+        match shape {
+            TypeShape::Felt252 => format!("calldata.push(({value_expr}).into());"),
+            TypeShape::Bool => format!(
+                "calldata.push(if {value_expr} {{ starknet::core::types::FieldElement::ONE }} \
+                 else {{ starknet::core::types::FieldElement::ZERO }});"
+            ),
+            TypeShape::U256 => format!(
+                "calldata.push(starknet::core::types::FieldElement::from(({value_expr}).low));\n        \
+                 calldata.push(starknet::core::types::FieldElement::from(({value_expr}).high));"
+            ),
+            TypeShape::Unit => String::new(),
+            TypeShape::ByteArray => format!(
+                "{{\n            let bytes = ({value_expr}).as_bytes();\n            \
+                 let full_word_count = bytes.len() / 31;\n            \
+                 calldata.push(starknet::core::types::FieldElement::from(full_word_count));\n            \
+                 for chunk in bytes[..full_word_count * 31].chunks(31) {{\n                \
+                 calldata.push(starknet::core::types::FieldElement::from_byte_slice_be(chunk).unwrap());\n            \
+                 }}\n            \
+                 let pending = &bytes[full_word_count * 31..];\n            \
+                 calldata.push(starknet::core::types::FieldElement::from_byte_slice_be(pending).unwrap());\n            \
+                 calldata.push(starknet::core::types::FieldElement::from(pending.len()));\n        }}"
+            ),
+            TypeShape::Array(element) => {
+                let element_stmt = self.pack_shape("element", element);
+                format!(
+                    "calldata.push(starknet::core::types::FieldElement::from(({value_expr}).len()));\n        \
+                     for element in &{value_expr} {{\n            {element_stmt}\n        }}"
+                )
+            }
+            TypeShape::Struct { fields, .. } => fields
+                .iter()
+                .map(|(name, field_shape)| {
+                    self.pack_shape(&format!("({value_expr}).{name}"), field_shape)
+                })
+                .collect::<Vec<_>>()
+                .join("\n        "),
+            TypeShape::Enum { name, variants } => {
+                let arms = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (variant_name, payload_shape))| {
+                        if matches!(payload_shape, TypeShape::Unit) {
+                            format!(
+                                "{name}::{variant_name} => {{\n                \
+                                 calldata.push(starknet::core::types::FieldElement::from({index}u32));\n            }}"
+                            )
+                        } else {
+                            let payload_stmt = self.pack_shape("payload", payload_shape);
+                            format!(
+                                "{name}::{variant_name}(payload) => {{\n                \
+                                 calldata.push(starknet::core::types::FieldElement::from({index}u32));\n                \
+                                 {payload_stmt}\n            }}"
+                            )
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",\n            ");
+                format!("match {value_expr} {{\n            {arms},\n        }}")
+            }
+            TypeShape::Opaque => format!("calldata.push(({value_expr}).into());"),
+        }
+    }
+
+    fn render(&self, contract_name: &str, methods: &[MethodBinding]) -> String {
+        let mut out = format!(
+            "pub struct {contract_name}Client {{\n    address: starknet::core::types::FieldElement,\n}}\n\nimpl {contract_name}Client {{\n"
+        );
+        for method in methods {
+            let args = render_args(&method.params, ": ");
+            out += &format!(
+                "    pub fn {}(&self, {args}) -> (starknet::core::types::FieldElement, Vec<starknet::core::types::FieldElement>) {{\n        \
+                 const SELECTOR: &str = \"{}\";\n        \
+                 let selector = starknet::core::types::FieldElement::from_hex_be(SELECTOR).unwrap();\n        \
+                 let mut calldata = Vec::new();\n",
+                method.name, method.selector
+            );
+            for param in &method.params {
+                let stmt = self.pack_shape(&param.name, &param.shape);
+                if !stmt.is_empty() {
+                    out += &format!("        {stmt}\n");
+                }
+            }
+            out += "        (selector, calldata)\n    }\n";
+        }
+        out += "}\n";
+        out
+    }
+}
+
+/// Emits a TypeScript caller class with the same method-per-entry-point shape as
+/// [`RustBackend`]. Enum values are expected as `{ variant: string, payload: ... }`.
+pub struct TypeScriptBackend;
+
+impl BindingsBackend for TypeScriptBackend {
+    fn extension(&self) -> &'static str {
+        "ts"
+    }
+
+    fn map_type(&self, cairo_type: &str) -> String {
+        match cairo_type {
+            "felt252" | "u256" => "bigint".to_string(),
+            "bool" => "boolean".to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    fn pack_shape(&self, value_expr: &str, shape: &TypeShape) -> String {
+        match shape {
+            TypeShape::Felt252 => format!("calldata.push(BigInt({value_expr}));"),
+            TypeShape::Bool => format!("calldata.push({value_expr} ? 1n : 0n);"),
+            TypeShape::U256 => format!(
+                "calldata.push(BigInt.asUintN(128, BigInt({value_expr})));\n    \
+                 calldata.push(BigInt({value_expr}) >> 128n);"
+            ),
+            TypeShape::Unit => String::new(),
+            TypeShape::ByteArray => format!(
+                "{{\n      const bytes = new TextEncoder().encode({value_expr});\n      \
+                 const fullWordCount = Math.floor(bytes.length / 31);\n      \
+                 calldata.push(BigInt(fullWordCount));\n      \
+                 for (let i = 0; i < fullWordCount; i++) {{\n        \
+                 calldata.push(bytes.slice(i * 31, i * 31 + 31).reduce((acc, b) => (acc << 8n) | BigInt(b), 0n));\n      \
+                 }}\n      \
+                 const pending = bytes.slice(fullWordCount * 31);\n      \
+                 calldata.push(pending.reduce((acc, b) => (acc << 8n) | BigInt(b), 0n));\n      \
+                 calldata.push(BigInt(pending.length));\n    }}"
+            ),
+            TypeShape::Array(element) => {
+                let element_stmt = self.pack_shape("element", element);
+                format!(
+                    "calldata.push(BigInt(({value_expr}).length));\n    \
+                     for (const element of {value_expr}) {{\n      {element_stmt}\n    }}"
+                )
+            }
+            TypeShape::Struct { fields, .. } => fields
+                .iter()
+                .map(|(name, field_shape)| self.pack_shape(&format!("{value_expr}.{name}"), field_shape))
+                .collect::<Vec<_>>()
+                .join("\n    "),
+            TypeShape::Enum { variants, .. } => variants
+                .iter()
+                .enumerate()
+                .map(|(index, (variant_name, payload_shape))| {
+                    let payload_stmt = self.pack_shape(&format!("{value_expr}.payload"), payload_shape);
+                    format!(
+                        "if ({value_expr}.variant === \"{variant_name}\") {{\n      \
+                         calldata.push({index}n);\n      {payload_stmt}\n    }}"
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" else "),
+            TypeShape::Opaque => format!("calldata.push(BigInt({value_expr}));"),
+        }
+    }
+
+    fn render(&self, contract_name: &str, methods: &[MethodBinding]) -> String {
+        let mut out =
+            format!("export class {contract_name}Client {{\n  constructor(private address: string) {{}}\n\n");
+        for method in methods {
+            let args = render_args(&method.params, ": ");
+            out += &format!(
+                "  {}({args}): {{ selector: string; calldata: bigint[] }} {{\n    \
+                 const selector = \"{}\";\n    const calldata: bigint[] = [];\n",
+                method.name, method.selector
+            );
+            for param in &method.params {
+                let stmt = self.pack_shape(&param.name, &param.shape);
+                if !stmt.is_empty() {
+                    out += &format!("    {stmt}\n");
+                }
+            }
+            out += "    return { selector, calldata };\n  }\n\n";
+        }
+        out += "}\n";
+        out
+    }
+}
+
+/// Joins a method's parameters into a comma-separated `name<sep>type` list.
+fn render_args(params: &[ParamBinding], sep: &str) -> String {
+    params
+        .iter()
+        .map(|param| format!("{}{sep}{}", param.name, param.mapped_type))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
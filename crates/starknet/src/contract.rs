@@ -4,9 +4,13 @@ use diagnostics::ToOption;
 use filesystem::ids::CrateId;
 use num_bigint::BigUint;
 use semantic::db::SemanticGroup;
+use semantic::Mutability;
+use serde_json::json;
 use sha3::{Digest, Keccak256};
 
-use crate::plugin::{ABI_TRAIT, CONTRACT_ATTR, EXTERNAL_MODULE};
+use crate::plugin::{
+    ABI_TRAIT, CONSTRUCTOR_MODULE, CONTRACT_ATTR, EVENT_TYPE, EXTERNAL_MODULE, L1_HANDLER_MODULE,
+};
 
 #[cfg(test)]
 #[path = "contract_test.rs"]
@@ -35,31 +39,42 @@ pub fn starknet_keccak(data: &[u8]) -> BigUint {
     BigUint::from_bytes_be(&result)
 }
 
-/// Finds the inline modules annotated as contracts in the given crate_ids and
-/// returns the corresponding ContractDeclarations.
+/// Finds the modules annotated as contracts in the given crate_ids - whether declared as
+/// inline `mod { ... }` blocks or in their own files, at any depth - and returns the
+/// corresponding ContractDeclarations.
 pub fn find_contracts(db: &dyn SemanticGroup, crate_ids: &[CrateId]) -> Vec<ContractDeclaration> {
     let mut contracts = vec![];
     for crate_id in crate_ids {
-        let modules = db.crate_modules(*crate_id);
-        for module_id in modules.iter() {
-            let Ok(submodules) = db.module_submodules(*module_id) else {
-                continue;
-            };
-
-            for module_id in submodules {
-                if let ModuleId::Submodule(submodule_id) = module_id {
-                    if let Ok(attrs) = db.module_attributes(module_id) {
-                        if attrs.iter().any(|attr| attr.id == CONTRACT_ATTR) {
-                            contracts.push(ContractDeclaration { submodule_id });
-                        };
-                    }
-                }
-            }
+        for module_id in db.crate_modules(*crate_id).iter() {
+            find_contracts_in_module(db, *module_id, &mut contracts);
         }
     }
     contracts
 }
 
+/// Recursively walks `module_id`'s full module tree - inline and file-backed submodules
+/// alike - collecting every submodule annotated with `CONTRACT_ATTR`.
+fn find_contracts_in_module(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    contracts: &mut Vec<ContractDeclaration>,
+) {
+    let Ok(submodules) = db.module_submodules(module_id) else {
+        return;
+    };
+
+    for module_id in submodules {
+        if let ModuleId::Submodule(submodule_id) = module_id {
+            if let Ok(attrs) = db.module_attributes(module_id) {
+                if attrs.iter().any(|attr| attr.id == CONTRACT_ATTR) {
+                    contracts.push(ContractDeclaration { submodule_id });
+                };
+            }
+            find_contracts_in_module(db, module_id, contracts);
+        }
+    }
+}
+
 /// Returns the list of external functions for a given contract.
 pub fn get_external_functions(
     db: &(dyn SemanticGroup + 'static),
@@ -81,6 +96,84 @@ pub fn get_external_functions(
     }
 }
 
+/// Returns the constructor function of the given contract, or `None` if it doesn't
+/// declare one.
+pub fn get_constructor_function(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Option<FreeFunctionId>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    match db
+        .module_items(generated_module_id)
+        .to_option()
+        .with_context(|| "Failed to get generated module items.")?
+        .items
+        .get(CONSTRUCTOR_MODULE)
+    {
+        Some(ModuleItemId::Submodule(constructor_module_id)) => Ok(db
+            .module_free_functions(ModuleId::Submodule(*constructor_module_id))
+            .to_option()
+            .with_context(|| "Failed to get module items.")?
+            .first()
+            .copied()),
+        _ => Ok(None),
+    }
+}
+
+/// Returns the list of l1_handler functions for a given contract, or an empty list if
+/// it doesn't declare any.
+pub fn get_l1_handler_functions(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<FreeFunctionId>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    match db
+        .module_items(generated_module_id)
+        .to_option()
+        .with_context(|| "Failed to get generated module items.")?
+        .items
+        .get(L1_HANDLER_MODULE)
+    {
+        Some(ModuleItemId::Submodule(l1_handler_module_id)) => Ok(db
+            .module_free_functions(ModuleId::Submodule(*l1_handler_module_id))
+            .to_option()
+            .with_context(|| "Failed to get module items.")?),
+        _ => Ok(vec![]),
+    }
+}
+
+/// An event declared by a contract, keyed by its `starknet_keccak` selector.
+pub struct ContractEvent {
+    pub name: String,
+    pub selector: BigUint,
+}
+
+/// Returns the list of events declared by a given contract.
+pub fn get_events(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<Vec<ContractEvent>> {
+    let generated_module_id = get_generated_contract_module(db, contract)?;
+    let event_enum_id = match db
+        .module_items(generated_module_id)
+        .to_option()
+        .with_context(|| "Failed to get generated module items.")?
+        .items
+        .get(EVENT_TYPE)
+    {
+        Some(ModuleItemId::Enum(event_enum_id)) => *event_enum_id,
+        _ => anyhow::bail!("Failed to get the Event type."),
+    };
+
+    Ok(db
+        .enum_variants(event_enum_id)
+        .to_option()
+        .with_context(|| "Failed to get event variants.")?
+        .keys()
+        .map(|name| ContractEvent { name: name.clone(), selector: starknet_keccak(name.as_bytes()) })
+        .collect())
+}
+
 /// Returns the ABI trait of the given contract.
 pub fn get_abi(
     db: &(dyn SemanticGroup + 'static),
@@ -99,6 +192,65 @@ pub fn get_abi(
     }
 }
 
+/// Builds the canonical ABI JSON document for a contract: every external function
+/// together with its computed selector, parameter/return types, and state-mutability.
+pub fn emit_contract_abi(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<serde_json::Value> {
+    let abi_trait = get_abi(db, contract)?;
+    let trait_functions = db
+        .trait_functions(abi_trait)
+        .to_option()
+        .with_context(|| "Failed to get ABI trait functions.")?;
+
+    let mut functions = vec![];
+    for (name, trait_function_id) in trait_functions.iter() {
+        let signature = db
+            .trait_function_signature(*trait_function_id)
+            .to_option()
+            .with_context(|| format!("Failed to get the signature of `{name}`."))?;
+
+        let inputs: Vec<_> = signature
+            .params
+            .iter()
+            .filter(|param| param.name != "self")
+            .map(|param| {
+                json!({
+                    "name": param.name.to_string(),
+                    "type": param.ty.format(db.upcast()),
+                })
+            })
+            .collect();
+
+        let outputs = if signature.return_type.is_unit(db.upcast()) {
+            vec![]
+        } else {
+            vec![json!({ "type": signature.return_type.format(db.upcast()) })]
+        };
+
+        functions.push(json!({
+            "type": "function",
+            "name": name.to_string(),
+            "inputs": inputs,
+            "outputs": outputs,
+            "state_mutability": state_mutability(&signature),
+            "selector": starknet_keccak(name.as_bytes()).to_str_radix(16),
+        }));
+    }
+
+    Ok(json!({ "abi": functions }))
+}
+
+/// External functions taking their `self` parameter by `ref` mutate contract storage and
+/// are reported as `external`; functions taking a snapshot are `view`.
+fn state_mutability(signature: &semantic::Signature) -> &'static str {
+    match signature.params.first().map(|param| &param.mutability) {
+        Some(Mutability::Reference) => "external",
+        _ => "view",
+    }
+}
+
 /// Returns the generated contract module.
 fn get_generated_contract_module(
     db: &(dyn SemanticGroup + 'static),
@@ -0,0 +1,16 @@
+use num_bigint::BigUint;
+
+use super::starknet_keccak;
+
+#[test]
+fn truncates_to_250_bits() {
+    // The top six bits of the first byte must always be cleared.
+    let selector = starknet_keccak(b"transfer");
+    assert!(selector < (BigUint::from(1u8) << 250u32));
+}
+
+#[test]
+fn is_deterministic() {
+    assert_eq!(starknet_keccak(b"balance_of"), starknet_keccak(b"balance_of"));
+    assert_ne!(starknet_keccak(b"balance_of"), starknet_keccak(b"transfer"));
+}
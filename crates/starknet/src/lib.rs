@@ -0,0 +1,5 @@
+pub mod bindgen;
+pub mod contract;
+pub mod manifest;
+pub mod plugin;
+pub mod transformer;
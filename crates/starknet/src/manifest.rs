@@ -0,0 +1,90 @@
+use anyhow::Context;
+use defs::ids::{FreeFunctionId, LanguageElementId};
+use filesystem::ids::CrateId;
+use semantic::db::SemanticGroup;
+use serde::Serialize;
+
+use crate::contract::{
+    find_contracts, get_constructor_function, get_events, get_external_functions,
+    get_l1_handler_functions, starknet_keccak, ContractDeclaration,
+};
+
+/// A serializable, stable description of every contract a set of crates compiled to.
+#[derive(Serialize)]
+pub struct ContractManifest {
+    pub contracts: Vec<ContractManifestEntry>,
+}
+
+/// The manifest entry for a single contract declaration.
+#[derive(Serialize)]
+pub struct ContractManifestEntry {
+    /// The fully-qualified path of the module that defines the contract.
+    pub module_path: String,
+    /// The contract's class selector: the `starknet_keccak` of its module path.
+    pub class_selector: String,
+    pub external_functions: Vec<ManifestFunction>,
+    pub constructor: Option<ManifestFunction>,
+    pub l1_handler_functions: Vec<ManifestFunction>,
+    pub events: Vec<ManifestFunction>,
+}
+
+/// A named entry point or event, alongside its `starknet_keccak` selector.
+#[derive(Serialize)]
+pub struct ManifestFunction {
+    pub name: String,
+    pub selector: String,
+}
+
+/// Builds a manifest describing every contract found in `crate_ids`, recording its
+/// module path, computed selectors, and entry-point/event names.
+pub fn build_contract_manifest(
+    db: &(dyn SemanticGroup + 'static),
+    crate_ids: &[CrateId],
+) -> anyhow::Result<ContractManifest> {
+    let contracts = find_contracts(db, crate_ids)
+        .iter()
+        .map(|contract| build_entry(db, contract))
+        .collect::<anyhow::Result<_>>()?;
+    Ok(ContractManifest { contracts })
+}
+
+/// Builds the manifest entry for a single contract declaration.
+fn build_entry(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+) -> anyhow::Result<ContractManifestEntry> {
+    let module_path = contract.submodule_id.full_path(db.upcast());
+
+    let external_functions = get_external_functions(db, contract)
+        .with_context(|| format!("Failed to get the external functions of `{module_path}`."))?
+        .into_iter()
+        .map(|function_id| named_function(db, function_id))
+        .collect();
+    let constructor =
+        get_constructor_function(db, contract)?.map(|function_id| named_function(db, function_id));
+    let l1_handler_functions = get_l1_handler_functions(db, contract)?
+        .into_iter()
+        .map(|function_id| named_function(db, function_id))
+        .collect();
+    let events = get_events(db, contract)?
+        .into_iter()
+        .map(|event| ManifestFunction { name: event.name, selector: event.selector.to_str_radix(16) })
+        .collect();
+
+    Ok(ContractManifestEntry {
+        class_selector: starknet_keccak(module_path.as_bytes()).to_str_radix(16),
+        module_path,
+        external_functions,
+        constructor,
+        l1_handler_functions,
+        events,
+    })
+}
+
+/// Builds a [`ManifestFunction`] from a free function id, computing its selector from
+/// its name the same way as ABI entries and events.
+fn named_function(db: &(dyn SemanticGroup + 'static), function_id: FreeFunctionId) -> ManifestFunction {
+    let name = function_id.name(db.upcast()).to_string();
+    let selector = starknet_keccak(name.as_bytes()).to_str_radix(16);
+    ManifestFunction { name, selector }
+}
@@ -0,0 +1,265 @@
+use anyhow::Context;
+use defs::ids::LanguageElementId;
+use diagnostics::ToOption;
+use num_bigint::BigUint;
+use semantic::db::SemanticGroup;
+use semantic::types::{ConcreteTypeId, TypeLongId};
+use semantic::{GenericArgumentId, TypeId};
+use thiserror::Error;
+
+use crate::contract::get_abi;
+use crate::contract::ContractDeclaration;
+
+#[cfg(test)]
+#[path = "transformer_test.rs"]
+mod test;
+
+/// The 31-byte word size used by StarkNet's `ByteArray` calldata layout.
+const BYTE_ARRAY_WORD_LEN: usize = 31;
+
+/// An error produced while transforming human-readable arguments into calldata, pointing
+/// at the offending argument.
+#[derive(Error, Debug)]
+pub enum TransformerError {
+    #[error("Function `{0}` not found in the contract ABI.")]
+    FunctionNotFound(String),
+    #[error("Expected {expected} argument(s) for `{function}`, got {actual}.")]
+    ArityMismatch { function: String, expected: usize, actual: usize },
+    #[error("Argument #{index} (`{value}`) is not a valid `{ty}`.")]
+    InvalidArgument { index: usize, value: String, ty: String },
+}
+
+/// Serializes `args`, a list of human-readable argument tokens, into calldata for
+/// `function_name` on `contract`, using the contract's ABI trait as the type schema.
+///
+/// The number of consumed tokens must exactly match the function's arity, and `u256`
+/// and `ByteArray` arguments are packed into StarkNet's canonical limb/word layout.
+///
+/// A struct/enum/array argument that itself nests another struct/enum/array must wrap
+/// that nested value in parentheses (e.g. a struct field `(1;2)` for a 2-field nested
+/// struct, or an array element `(A:1)` for an enum), so the outer `;`/`,`/`:` split knows
+/// where the nested value ends. Scalar fields need no wrapping. Nesting recurses to any
+/// depth - a struct field that is itself a struct of structs just needs one `(...)` layer
+/// per level, e.g. `(1;(2;3))` for a field whose own field is a 2-field struct.
+pub fn transform(
+    db: &(dyn SemanticGroup + 'static),
+    contract: &ContractDeclaration,
+    function_name: &str,
+    args: &[String],
+) -> anyhow::Result<Vec<BigUint>> {
+    let abi_trait = get_abi(db, contract)?;
+    let trait_functions = db
+        .trait_functions(abi_trait)
+        .to_option()
+        .with_context(|| "Failed to get ABI trait functions.")?;
+
+    let trait_function_id = trait_functions
+        .get(function_name)
+        .ok_or_else(|| TransformerError::FunctionNotFound(function_name.to_string()))?;
+
+    let signature = db
+        .trait_function_signature(*trait_function_id)
+        .to_option()
+        .with_context(|| format!("Failed to get the signature of `{function_name}`."))?;
+
+    let params: Vec<_> = signature.params.iter().filter(|param| param.name != "self").collect();
+    if params.len() != args.len() {
+        anyhow::bail!(TransformerError::ArityMismatch {
+            function: function_name.to_string(),
+            expected: params.len(),
+            actual: args.len(),
+        });
+    }
+
+    let mut calldata = vec![];
+    for (index, (param, arg)) in params.iter().zip(args.iter()).enumerate() {
+        serialize_arg(db, param.ty, arg, index, &mut calldata)?;
+    }
+    Ok(calldata)
+}
+
+/// Serializes a single human-readable `value` according to its Cairo `ty`, appending the
+/// resulting felts to `calldata`.
+///
+/// Scalars and `ByteArray`s are matched by their formatted type name (see
+/// [`serialize_scalar`]). `Array<T>`/`Span<T>` and structs/enums are resolved from `ty`
+/// itself - via [`array_element_type`] and `concrete_struct_members`/
+/// `concrete_enum_variants` respectively - so that `T`'s own structure (including nested
+/// structs/enums/arrays) is available for recursion, not just its formatted name. Because
+/// array/enum-payload elements recurse back into this same function with the element's own
+/// `TypeId`, `Array<MyStruct>`/`Array<MyEnum>` resolve their element's members/variants
+/// exactly like a top-level struct/enum argument would.
+fn serialize_arg(
+    db: &(dyn SemanticGroup + 'static),
+    ty: TypeId,
+    value: &str,
+    index: usize,
+    calldata: &mut Vec<BigUint>,
+) -> anyhow::Result<()> {
+    let ty_str = ty.format(db.upcast());
+    let invalid = || TransformerError::InvalidArgument {
+        index,
+        value: value.to_string(),
+        ty: ty_str.clone(),
+    };
+
+    if serialize_scalar(&ty_str, value, index, calldata)?.is_some() {
+        return Ok(());
+    }
+
+    if let Some(element_ty) = array_element_type(db, ty) {
+        let elements: Vec<&str> =
+            if value.is_empty() { vec![] } else { split_top_level(value, ',') };
+        calldata.push(BigUint::from(elements.len()));
+        for element in elements {
+            serialize_arg(db, element_ty, strip_parens(element.trim()), index, calldata)?;
+        }
+        return Ok(());
+    }
+
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            let members = db
+                .concrete_struct_members(concrete_struct_id)
+                .to_option()
+                .with_context(|| format!("Failed to get the members of `{ty_str}`."))?;
+
+            let fields = split_top_level(value, ';');
+            if fields.len() != members.len() {
+                anyhow::bail!(invalid());
+            }
+            for (member, field_value) in members.values().zip(fields.iter()) {
+                serialize_arg(db, member.ty, strip_parens(field_value.trim()), index, calldata)?;
+            }
+            Ok(())
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            let variants = db
+                .concrete_enum_variants(concrete_enum_id)
+                .to_option()
+                .with_context(|| format!("Failed to get the variants of `{ty_str}`."))?;
+
+            let (variant_name, payload) = value.split_once(':').unwrap_or((value, ""));
+            let (variant_index, variant) = variants
+                .iter()
+                .enumerate()
+                .find(|(_, variant)| variant.id.name(db.upcast()) == variant_name)
+                .ok_or_else(invalid)?;
+
+            calldata.push(BigUint::from(variant_index));
+            serialize_arg(db, variant.ty, strip_parens(payload.trim()), index, calldata)
+        }
+        _ => anyhow::bail!(invalid()),
+    }
+}
+
+/// Handles every argument type expressible purely from its formatted name: `felt252`,
+/// `u256`, `bool`, `ByteArray`, and an empty `()` (e.g. a payload-less enum variant),
+/// which serializes to zero felts. Returns `Ok(None)` for any other type name, leaving it
+/// to the caller to resolve arrays, structs, and enums via the semantic DB.
+fn serialize_scalar(
+    ty_str: &str,
+    value: &str,
+    index: usize,
+    calldata: &mut Vec<BigUint>,
+) -> anyhow::Result<Option<()>> {
+    let invalid = || TransformerError::InvalidArgument {
+        index,
+        value: value.to_string(),
+        ty: ty_str.to_string(),
+    };
+
+    match ty_str {
+        "u256" => {
+            let n = value.parse::<BigUint>().map_err(|_| invalid())?;
+            let (low, high) = split_u256(n);
+            calldata.push(low);
+            calldata.push(high);
+        }
+        "felt252" => {
+            calldata.push(value.parse::<BigUint>().map_err(|_| invalid())?);
+        }
+        "bool" => {
+            calldata.push(match value {
+                "true" => BigUint::from(1u8),
+                "false" => BigUint::from(0u8),
+                _ => anyhow::bail!(invalid()),
+            });
+        }
+        "ByteArray" => serialize_byte_array(value, calldata),
+        "()" if value.is_empty() => {}
+        _ => return Ok(None),
+    }
+    Ok(Some(()))
+}
+
+/// Returns the element type of `ty` if it's a concrete `Array<T>` or `Span<T>`, or `None`
+/// for any other type. Shared with `bindgen`, which needs the same element type to
+/// generate calldata-packing code for array/span parameters.
+pub(crate) fn array_element_type(db: &(dyn SemanticGroup + 'static), ty: TypeId) -> Option<TypeId> {
+    let TypeLongId::Concrete(ConcreteTypeId::Extern(concrete_extern_type_id)) =
+        db.lookup_intern_type(ty)
+    else {
+        return None;
+    };
+    let long_id = db.lookup_intern_concrete_extern_type(concrete_extern_type_id);
+    let extern_name = long_id.extern_type_id.name(db.upcast());
+    if extern_name != "Array" && extern_name != "Span" {
+        return None;
+    }
+    match long_id.generic_args.first() {
+        Some(GenericArgumentId::Type(element_ty)) => Some(*element_ty),
+        _ => None,
+    }
+}
+
+/// Splits `value` on `delim`, but only at nesting depth 0 - a `(...)`-wrapped nested
+/// struct/enum/array value is treated as a single opaque token, not split into.
+fn split_top_level(value: &str, delim: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0i32;
+    let mut start = 0usize;
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == delim && depth == 0 => {
+                parts.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+/// Strips a single layer of wrapping parentheses, if present, from a nested
+/// struct/enum/array token.
+fn strip_parens(value: &str) -> &str {
+    value.strip_prefix('(').and_then(|rest| rest.strip_suffix(')')).unwrap_or(value)
+}
+
+/// Splits a `u256` into its low and high 128-bit limbs, per StarkNet's canonical layout.
+fn split_u256(n: BigUint) -> (BigUint, BigUint) {
+    let mask = (BigUint::from(1u8) << 128u32) - BigUint::from(1u8);
+    let low = &n & &mask;
+    let high = n >> 128u32;
+    (low, high)
+}
+
+/// Packs a short/long string literal into 31-byte words: full words first, then a
+/// pending (partial) word and its byte length.
+fn serialize_byte_array(value: &str, calldata: &mut Vec<BigUint>) {
+    let bytes = value.as_bytes();
+    let full_word_count = bytes.len() / BYTE_ARRAY_WORD_LEN;
+
+    calldata.push(BigUint::from(full_word_count));
+    for word in bytes[..full_word_count * BYTE_ARRAY_WORD_LEN].chunks(BYTE_ARRAY_WORD_LEN) {
+        calldata.push(BigUint::from_bytes_be(word));
+    }
+
+    let pending = &bytes[full_word_count * BYTE_ARRAY_WORD_LEN..];
+    calldata.push(BigUint::from_bytes_be(pending));
+    calldata.push(BigUint::from(pending.len()));
+}
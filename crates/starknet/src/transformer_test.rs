@@ -0,0 +1,108 @@
+use num_bigint::BigUint;
+
+use super::{serialize_byte_array, split_top_level, split_u256, strip_parens};
+
+#[test]
+fn byte_array_empty_value() {
+    let mut calldata = vec![];
+    serialize_byte_array("", &mut calldata);
+    assert_eq!(
+        calldata,
+        vec![BigUint::from(0u8), BigUint::from_bytes_be(b""), BigUint::from(0u8)]
+    );
+}
+
+#[test]
+fn byte_array_short_value() {
+    let mut calldata = vec![];
+    serialize_byte_array("hello", &mut calldata);
+    assert_eq!(
+        calldata,
+        vec![BigUint::from(0u8), BigUint::from_bytes_be(b"hello"), BigUint::from(5u8)]
+    );
+}
+
+#[test]
+fn byte_array_exact_multiple_of_31() {
+    let value = "a".repeat(31);
+    let mut calldata = vec![];
+    serialize_byte_array(&value, &mut calldata);
+    assert_eq!(
+        calldata,
+        vec![
+            BigUint::from(1u8),
+            BigUint::from_bytes_be(value.as_bytes()),
+            BigUint::from_bytes_be(b""),
+            BigUint::from(0u8),
+        ]
+    );
+}
+
+#[test]
+fn byte_array_multiple_words_with_pending_tail() {
+    let value = format!("{}{}", "a".repeat(31), "bc");
+    let mut calldata = vec![];
+    serialize_byte_array(&value, &mut calldata);
+    assert_eq!(
+        calldata,
+        vec![
+            BigUint::from(1u8),
+            BigUint::from_bytes_be("a".repeat(31).as_bytes()),
+            BigUint::from_bytes_be(b"bc"),
+            BigUint::from(2u8),
+        ]
+    );
+}
+
+#[test]
+fn u256_splits_zero_into_two_zero_limbs() {
+    assert_eq!(split_u256(BigUint::from(0u8)), (BigUint::from(0u8), BigUint::from(0u8)));
+}
+
+#[test]
+fn u256_low_limb_only() {
+    let n = BigUint::from(u128::MAX);
+    assert_eq!(split_u256(n.clone()), (n, BigUint::from(0u8)));
+}
+
+#[test]
+fn u256_crosses_the_128_bit_boundary() {
+    // 2^128 is the smallest value whose high limb is nonzero.
+    let n = BigUint::from(1u8) << 128u32;
+    assert_eq!(split_u256(n), (BigUint::from(0u8), BigUint::from(1u8)));
+}
+
+#[test]
+fn u256_max_value_fills_both_limbs() {
+    let max_u256 = (BigUint::from(1u8) << 256u32) - BigUint::from(1u8);
+    let max_limb = BigUint::from(u128::MAX);
+    assert_eq!(split_u256(max_u256), (max_limb.clone(), max_limb));
+}
+
+#[test]
+fn split_top_level_ignores_delimiters_inside_parens() {
+    assert_eq!(split_top_level("1;(2;3);4", ';'), vec!["1", "(2;3)", "4"]);
+}
+
+#[test]
+fn split_top_level_with_no_nesting() {
+    assert_eq!(split_top_level("1,2,3", ','), vec!["1", "2", "3"]);
+}
+
+#[test]
+fn split_top_level_handles_struct_of_structs_nesting() {
+    // A struct field that is itself a 2-field struct, nested two levels deep.
+    assert_eq!(split_top_level("1;(2;(3;4))", ';'), vec!["1", "(2;(3;4))"]);
+}
+
+#[test]
+fn split_top_level_handles_array_of_struct_elements() {
+    // Array<MyStruct> elements, each a parenthesized struct value.
+    assert_eq!(split_top_level("(1;2),(3;4),(5;6)", ','), vec!["(1;2)", "(3;4)", "(5;6)"]);
+}
+
+#[test]
+fn strip_parens_removes_one_layer() {
+    assert_eq!(strip_parens("(1;2)"), "1;2");
+    assert_eq!(strip_parens("1"), "1");
+}